@@ -0,0 +1,105 @@
+use accelerometer::error::Error as AccelerometerError;
+use accelerometer::vector::{F32x3, I16x3};
+#[cfg(feature = "out_f32")]
+use accelerometer::Accelerometer;
+use accelerometer::RawAccelerometer;
+use embedded_hal::blocking::i2c::{Write, WriteRead};
+
+use crate::Ism330Dhcx;
+
+impl<I2C, E> RawAccelerometer<I16x3> for Ism330Dhcx<I2C>
+where
+    I2C: WriteRead<Error = E> + Write<Error = E>,
+{
+    type Error = E;
+
+    /// Reads the raw `OUTX/Y/Z_A` registers, following the `lis2dh12` driver's
+    /// `RawAccelerometer` impl. Returns unscaled counts -- use
+    /// [`Accelerometer::accel_norm`] for a reading in `g`.
+    fn accel_raw(&mut self) -> Result<I16x3, AccelerometerError<E>> {
+        let xl = self.read_xl_raw().map_err(AccelerometerError::Bus)?;
+        Ok(I16x3::new(xl[0], xl[1], xl[2]))
+    }
+}
+
+/// Samples in `g`: the raw counts from [`RawAccelerometer::accel_raw`] scaled
+/// by whatever full-scale range (`FS_XL`) `CTRL1_XL` is currently configured
+/// for.
+#[cfg(feature = "out_f32")]
+impl<I2C, E> Accelerometer for Ism330Dhcx<I2C>
+where
+    I2C: WriteRead<Error = E> + Write<Error = E>,
+{
+    type Error = E;
+
+    /// Scales the raw counts by the full-scale range configured on `CTRL1_XL`.
+    ///
+    /// The HP/LPF2 path (`ctrl8xl.hp_slope_xl_en`, `ctrl8xl.hpcf`) is applied
+    /// on-chip before the sample reaches `OUTX/Y/Z_A`, so a reading already
+    /// reflects whichever filter is engaged -- no extra scaling is needed
+    /// here for that.
+    fn accel_norm(&mut self) -> Result<F32x3, AccelerometerError<E>> {
+        let raw = self.accel_raw()?;
+        let sensitivity = self.ctrl1xl.full_scale_g() / i16::MAX as f32;
+
+        Ok(F32x3::new(
+            raw.x as f32 * sensitivity,
+            raw.y as f32 * sensitivity,
+            raw.z as f32 * sensitivity,
+        ))
+    }
+
+    fn sample_rate(&mut self) -> Result<f32, AccelerometerError<E>> {
+        Ok(self.ctrl1xl.odr_hz())
+    }
+}
+
+#[cfg(all(test, feature = "out_f32"))]
+mod tests {
+    use super::*;
+    use crate::ctrl1xl::FsXl;
+    use crate::SlaveAddr;
+
+    struct MockI2c {
+        response: [u8; 6],
+    }
+
+    impl Write for MockI2c {
+        type Error = ();
+
+        fn write(&mut self, _addr: u8, _bytes: &[u8]) -> Result<(), Self::Error> {
+            Ok(())
+        }
+    }
+
+    impl WriteRead for MockI2c {
+        type Error = ();
+
+        fn write_read(
+            &mut self,
+            _addr: u8,
+            _bytes: &[u8],
+            buffer: &mut [u8],
+        ) -> Result<(), Self::Error> {
+            buffer.copy_from_slice(&self.response);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn accel_norm_scales_by_the_configured_full_scale_range() {
+        // z = i16::MAX counts, full scale = 4g -> z_g == 4.0 exactly.
+        let i2c = MockI2c {
+            response: [0x00, 0x00, 0x00, 0x00, 0xff, 0x7f],
+        };
+        let mut xl = Ism330Dhcx::new(i2c, SlaveAddr::Default);
+        xl.ctrl1xl
+            .set_fs_xl(&mut MockI2c { response: [0; 6] }, FsXl::G4)
+            .unwrap();
+
+        let sample = xl.accel_norm().unwrap();
+        assert_eq!(sample.x, 0.0);
+        assert_eq!(sample.y, 0.0);
+        assert_eq!(sample.z, 4.0);
+    }
+}
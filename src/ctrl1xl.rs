@@ -0,0 +1,163 @@
+use core::fmt;
+use embedded_hal::blocking::i2c::Write;
+
+use crate::Register;
+use crate::SlaveAddr;
+
+/// The CTRL1_XL register. Accelerometer control register 1 (r/w).
+///
+/// Accelerometer output data rate and full-scale selection.
+pub struct Ctrl1Xl {
+    address: u8,
+    value: u8,
+}
+
+impl fmt::Display for Ctrl1Xl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+impl fmt::Binary for Ctrl1Xl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:b}", self.value)
+    }
+}
+
+impl fmt::LowerHex for Ctrl1Xl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(&self.value, f)
+    }
+}
+
+/// Sub-address of the register.
+pub const ADDR: u8 = 0x10u8;
+
+/// Accelerometer full-scale selection. Refer to Table 47.
+const FS_XL_MASK: u8 = 0b11;
+const FS_XL_OFFSET: u8 = 2;
+
+/// Accelerometer output data rate selection. Refer to Table 46.
+const ODR_XL_MASK: u8 = 0b1111;
+const ODR_XL_OFFSET: u8 = 4;
+
+/// Accelerometer full-scale selection. Refer to Table 47.
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum FsXl {
+    G2,
+    G16,
+    G4,
+    G8,
+}
+
+/// Accelerometer output data rate selection. Refer to Table 46.
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum OdrXl {
+    PowerDown,
+    Hz12_5,
+    Hz26,
+    Hz52,
+    Hz104,
+    Hz208,
+    Hz416,
+    Hz833,
+    Hz1666,
+    Hz3332,
+    Hz6664,
+}
+
+impl Register for Ctrl1Xl {}
+
+fn set_fs_xl_bits(value: u8, fs: FsXl) -> u8 {
+    (value & !(FS_XL_MASK << FS_XL_OFFSET)) | ((fs as u8) << FS_XL_OFFSET)
+}
+
+fn set_odr_xl_bits(value: u8, odr: OdrXl) -> u8 {
+    (value & !(ODR_XL_MASK << ODR_XL_OFFSET)) | ((odr as u8) << ODR_XL_OFFSET)
+}
+
+impl Ctrl1Xl {
+    pub fn new(value: u8, address: SlaveAddr) -> Self {
+        Ctrl1Xl {
+            value,
+            address: address.addr(),
+        }
+    }
+
+    /// The resolved 7-bit I2C address this register writes to.
+    pub fn address(&self) -> u8 {
+        self.address
+    }
+
+    pub fn fs_xl(&self) -> FsXl {
+        match (self.value >> FS_XL_OFFSET) & FS_XL_MASK {
+            0 => FsXl::G2,
+            1 => FsXl::G16,
+            2 => FsXl::G4,
+            3 => FsXl::G8,
+            _ => panic!("Unreachable"),
+        }
+    }
+
+    pub fn set_fs_xl<I2C>(&mut self, i2c: &mut I2C, value: FsXl) -> Result<(), I2C::Error>
+    where
+        I2C: Write,
+    {
+        self.value = set_fs_xl_bits(self.value, value);
+        self.write(i2c, self.address, ADDR, self.value)
+    }
+
+    pub fn odr_xl(&self) -> OdrXl {
+        match (self.value >> ODR_XL_OFFSET) & ODR_XL_MASK {
+            0 => OdrXl::PowerDown,
+            1 => OdrXl::Hz12_5,
+            2 => OdrXl::Hz26,
+            3 => OdrXl::Hz52,
+            4 => OdrXl::Hz104,
+            5 => OdrXl::Hz208,
+            6 => OdrXl::Hz416,
+            7 => OdrXl::Hz833,
+            8 => OdrXl::Hz1666,
+            9 => OdrXl::Hz3332,
+            10 => OdrXl::Hz6664,
+            _ => panic!("Unreachable"),
+        }
+    }
+
+    pub fn set_odr_xl<I2C>(&mut self, i2c: &mut I2C, value: OdrXl) -> Result<(), I2C::Error>
+    where
+        I2C: Write,
+    {
+        self.value = set_odr_xl_bits(self.value, value);
+        self.write(i2c, self.address, ADDR, self.value)
+    }
+
+    /// The configured full-scale range, in g.
+    pub fn full_scale_g(&self) -> f32 {
+        match self.fs_xl() {
+            FsXl::G2 => 2.0,
+            FsXl::G4 => 4.0,
+            FsXl::G8 => 8.0,
+            FsXl::G16 => 16.0,
+        }
+    }
+
+    /// The configured output data rate, in Hz. `0.0` while powered down.
+    pub fn odr_hz(&self) -> f32 {
+        match self.odr_xl() {
+            OdrXl::PowerDown => 0.0,
+            OdrXl::Hz12_5 => 12.5,
+            OdrXl::Hz26 => 26.0,
+            OdrXl::Hz52 => 52.0,
+            OdrXl::Hz104 => 104.0,
+            OdrXl::Hz208 => 208.0,
+            OdrXl::Hz416 => 416.0,
+            OdrXl::Hz833 => 833.0,
+            OdrXl::Hz1666 => 1666.0,
+            OdrXl::Hz3332 => 3332.0,
+            OdrXl::Hz6664 => 6664.0,
+        }
+    }
+}
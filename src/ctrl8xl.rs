@@ -1,13 +1,19 @@
 use core::fmt;
 use embedded_hal::blocking::i2c::Write;
 
+#[cfg(feature = "async")]
+use embedded_hal_async::i2c::I2c;
+
 use crate::Register;
+#[cfg(feature = "async")]
+use crate::AsyncRegister;
+use crate::SlaveAddr;
 
 /// The CTRL8_XL register. Accelerometer control register 8 (r/w).
 ///
 /// Accelerometer High-pass and Low-pass filter configuration.
 pub struct Ctrl8Xl {
-    pub address: u8,
+    address: u8,
     value: u8,
 }
 
@@ -63,11 +69,43 @@ pub enum HPCF_Xl {
 }
 
 
+/// Which side of the accelerometer filter chain a [`Ctrl8Xl::cutoff_hz`]
+/// corner belongs to, per Table 61 of the datasheet.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum FilterCorner {
+    /// `HP_SLOPE_XL_EN` is `0`: the divisor selects the LPF2 low-pass corner.
+    LowPass,
+    /// `HP_SLOPE_XL_EN` is `1`: the divisor selects the slope/high-pass corner.
+    HighPass,
+}
+
 impl Register for Ctrl8Xl {}
 
+#[cfg(feature = "async")]
+impl AsyncRegister for Ctrl8Xl {}
+
+/// Sets `bit` in `value` to `set`, leaving the rest of the byte untouched.
+/// Shared by the blocking and async setters so the two paths can't drift.
+fn set_bit(value: u8, bit: u8, set: bool) -> u8 {
+    (value & !(1 << bit)) | ((set as u8) << bit)
+}
+
+/// Sets the `HPCF_XL` field in `value`, leaving the rest of the byte untouched.
+fn set_hpcf_bits(value: u8, hpcf: HPCF_Xl) -> u8 {
+    (value & !(HPCF_XL_MASK << HPCF_XL_OFFSET)) | ((hpcf as u8) << HPCF_XL_OFFSET)
+}
+
 impl Ctrl8Xl {
-    pub fn new(value: u8, address: u8) -> Self {
-        Ctrl8Xl { value, address }
+    pub fn new(value: u8, address: SlaveAddr) -> Self {
+        Ctrl8Xl {
+            value,
+            address: address.addr(),
+        }
+    }
+
+    /// The resolved 7-bit I2C address this register writes to.
+    pub fn address(&self) -> u8 {
+        self.address
     }
 
     /// HP or LPF2 cut-off fraction of ODR: ODR / hpcf.
@@ -85,6 +123,27 @@ impl Ctrl8Xl {
         }
     }
 
+    /// Effective -3 dB corner of the configured filter, in Hz, given the
+    /// accelerometer's current output data rate `odr_hz`.
+    ///
+    /// Per Table 61, the `HPCF_XL` divisor returned by [`Ctrl8Xl::hpcf`]
+    /// selects a low-pass corner when the slope/high-pass path is disabled
+    /// (`HP_SLOPE_XL_EN == 0`) and a high-pass corner when it is enabled, so
+    /// the returned [`FilterCorner`] tells the caller which one they got.
+    ///
+    /// This is plain `f32` division rather than a `libm` call: division is a
+    /// native float operation (unlike `sinf`/`powf`/...), so it's already
+    /// `no_std`/no-intrinsics friendly without pulling in the dependency.
+    pub fn cutoff_hz(&self, odr_hz: f32) -> (f32, FilterCorner) {
+        let corner = if self.value & (1 << HP_SLOPE_XL_EN) != 0 {
+            FilterCorner::HighPass
+        } else {
+            FilterCorner::LowPass
+        };
+
+        (odr_hz / self.hpcf(), corner)
+    }
+
     pub fn set_hpcf<I2C>(
         &mut self,
         i2c: &mut I2C,
@@ -93,11 +152,19 @@ impl Ctrl8Xl {
     where
         I2C: Write,
     {
-        self.value &= !(HPCF_XL_MASK << HPCF_XL_OFFSET);
-        self.value |= (value as u8) << HPCF_XL_OFFSET;
+        self.value = set_hpcf_bits(self.value, value);
         self.write(i2c, self.address, ADDR, self.value)
     }
 
+    #[cfg(feature = "async")]
+    pub async fn set_hpcf_async<I2C>(&mut self, i2c: &mut I2C, value: HPCF_Xl) -> Result<(), I2C::Error>
+    where
+        I2C: I2c,
+    {
+        self.value = set_hpcf_bits(self.value, value);
+        AsyncRegister::write(self, i2c, self.address, ADDR, self.value).await
+    }
+
     pub fn hp_slope_xl_en(&mut self) -> bool {
         self.value & (1 << HP_SLOPE_XL_EN) != 0
     }
@@ -106,11 +173,19 @@ impl Ctrl8Xl {
     where
         I2C: Write,
     {
-        self.value &= !(1 << HP_SLOPE_XL_EN);
-        self.value |= (value as u8) << HP_SLOPE_XL_EN;
+        self.value = set_bit(self.value, HP_SLOPE_XL_EN, value);
         self.write(i2c, self.address, ADDR, self.value)
     }
 
+    #[cfg(feature = "async")]
+    pub async fn set_hp_slope_xl_en_async<I2C>(&mut self, i2c: &mut I2C, value: bool) -> Result<(), I2C::Error>
+    where
+        I2C: I2c,
+    {
+        self.value = set_bit(self.value, HP_SLOPE_XL_EN, value);
+        AsyncRegister::write(self, i2c, self.address, ADDR, self.value).await
+    }
+
     pub fn low_pass_on_6d(&mut self) -> bool {
         self.value & (1 << LOW_PASS_ON_6D) != 0
     }
@@ -119,11 +194,19 @@ impl Ctrl8Xl {
     where
         I2C: Write,
     {
-        self.value &= !(1 << LOW_PASS_ON_6D);
-        self.value |= (value as u8) << LOW_PASS_ON_6D;
+        self.value = set_bit(self.value, LOW_PASS_ON_6D, value);
         self.write(i2c, self.address, ADDR, self.value)
     }
 
+    #[cfg(feature = "async")]
+    pub async fn set_low_pass_on_6d_async<I2C>(&mut self, i2c: &mut I2C, value: bool) -> Result<(), I2C::Error>
+    where
+        I2C: I2c,
+    {
+        self.value = set_bit(self.value, LOW_PASS_ON_6D, value);
+        AsyncRegister::write(self, i2c, self.address, ADDR, self.value).await
+    }
+
     pub fn fastsettl_mode(&mut self) -> bool {
         self.value & (1 << LOW_PASS_ON_6D) != 0
     }
@@ -132,11 +215,19 @@ impl Ctrl8Xl {
     where
         I2C: Write,
     {
-        self.value &= !(1 << FASTSETTL_MODE_XL);
-        self.value |= (value as u8) << FASTSETTL_MODE_XL;
+        self.value = set_bit(self.value, FASTSETTL_MODE_XL, value);
         self.write(i2c, self.address, ADDR, self.value)
     }
 
+    #[cfg(feature = "async")]
+    pub async fn set_fastsettl_mode_async<I2C>(&mut self, i2c: &mut I2C, value: bool) -> Result<(), I2C::Error>
+    where
+        I2C: I2c,
+    {
+        self.value = set_bit(self.value, FASTSETTL_MODE_XL, value);
+        AsyncRegister::write(self, i2c, self.address, ADDR, self.value).await
+    }
+
     pub fn hp_ref_mode(&mut self) -> bool {
         self.value & (1 << LOW_PASS_ON_6D) != 0
     }
@@ -145,9 +236,277 @@ impl Ctrl8Xl {
     where
         I2C: Write,
     {
-        self.value &= !(1 << HP_REF_MODE_XL);
-        self.value |= (value as u8) << HP_REF_MODE_XL;
+        self.value = set_bit(self.value, HP_REF_MODE_XL, value);
         self.write(i2c, self.address, ADDR, self.value)
     }
+
+    #[cfg(feature = "async")]
+    pub async fn set_hp_ref_mode_async<I2C>(&mut self, i2c: &mut I2C, value: bool) -> Result<(), I2C::Error>
+    where
+        I2C: I2c,
+    {
+        self.value = set_bit(self.value, HP_REF_MODE_XL, value);
+        AsyncRegister::write(self, i2c, self.address, ADDR, self.value).await
+    }
+}
+
+/// `HP_REF_MODE_XL` requires `HP_SLOPE_XL_EN` to be set, and `LOW_PASS_ON_6D`
+/// is only meaningful while the slope/high-pass path is disabled -- the
+/// datasheet forbids both combinations. Returned by [`XlFilterConfig::write`].
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum InvalidXlFilterConfig {
+    /// `hp_ref_mode` was set without `hp_slope_xl_en`.
+    HpRefModeRequiresHpSlope,
+    /// `low_pass_on_6d` was set while `hp_slope_xl_en` is also set.
+    LowPassOn6dRequiresHpSlopeDisabled,
+}
+
+/// Error from [`XlFilterConfig::write`]: either the combination was rejected
+/// before anything was sent over the bus, or the I2C write itself failed.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum XlFilterConfigError<E> {
+    InvalidConfig(InvalidXlFilterConfig),
+    I2c(E),
+}
+
+/// Builder for the CTRL8_XL filter chain.
+///
+/// The individual setters on [`Ctrl8Xl`] (`set_hp_slope_xl_en`,
+/// `set_low_pass_on_6d`, `set_fastsettl_mode`, `set_hp_ref_mode`,
+/// `set_hpcf`) let you assemble electrically invalid combinations one
+/// read-modify-write at a time. `XlFilterConfig` validates the whole
+/// combination up front and [`write`](Self::write)s it to CTRL8_XL in a
+/// single I2C transaction, so the register never passes through an
+/// inconsistent intermediate state.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct XlFilterConfig {
+    hp_slope_xl_en: bool,
+    low_pass_on_6d: bool,
+    fastsettl_mode: bool,
+    hp_ref_mode: bool,
+    hpcf: HPCF_Xl,
+}
+
+impl XlFilterConfig {
+    /// Starts a config with the given `HPCF_XL` divisor and every other
+    /// field disabled.
+    pub fn new(hpcf: HPCF_Xl) -> Self {
+        XlFilterConfig {
+            hp_slope_xl_en: false,
+            low_pass_on_6d: false,
+            fastsettl_mode: false,
+            hp_ref_mode: false,
+            hpcf,
+        }
+    }
+
+    pub fn hp_slope_xl_en(mut self, value: bool) -> Self {
+        self.hp_slope_xl_en = value;
+        self
+    }
+
+    pub fn low_pass_on_6d(mut self, value: bool) -> Self {
+        self.low_pass_on_6d = value;
+        self
+    }
+
+    pub fn fastsettl_mode(mut self, value: bool) -> Self {
+        self.fastsettl_mode = value;
+        self
+    }
+
+    pub fn hp_ref_mode(mut self, value: bool) -> Self {
+        self.hp_ref_mode = value;
+        self
+    }
+
+    fn validate(&self) -> Result<(), InvalidXlFilterConfig> {
+        if self.hp_ref_mode && !self.hp_slope_xl_en {
+            return Err(InvalidXlFilterConfig::HpRefModeRequiresHpSlope);
+        }
+        if self.low_pass_on_6d && self.hp_slope_xl_en {
+            return Err(InvalidXlFilterConfig::LowPassOn6dRequiresHpSlopeDisabled);
+        }
+        Ok(())
+    }
+
+    fn byte(&self) -> u8 {
+        let value = set_bit(0, HP_SLOPE_XL_EN, self.hp_slope_xl_en);
+        let value = set_bit(value, LOW_PASS_ON_6D, self.low_pass_on_6d);
+        let value = set_bit(value, FASTSETTL_MODE_XL, self.fastsettl_mode);
+        let value = set_bit(value, HP_REF_MODE_XL, self.hp_ref_mode);
+        set_hpcf_bits(value, self.hpcf)
+    }
+
+    /// Validates the combination and, if it's valid, commits it to CTRL8_XL
+    /// in a single write.
+    pub fn write<I2C>(
+        &self,
+        xl: &mut Ctrl8Xl,
+        i2c: &mut I2C,
+    ) -> Result<(), XlFilterConfigError<I2C::Error>>
+    where
+        I2C: Write,
+    {
+        self.validate().map_err(XlFilterConfigError::InvalidConfig)?;
+        xl.value = self.byte();
+        xl.write(i2c, xl.address, ADDR, xl.value)
+            .map_err(XlFilterConfigError::I2c)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cutoff_hz_picks_low_pass_corner_when_hp_slope_disabled() {
+        let xl = Ctrl8Xl::new(set_hpcf_bits(0, HPCF_Xl::ODR_SLOPE_4), SlaveAddr::Default);
+        assert_eq!(xl.cutoff_hz(400.0), (100.0, FilterCorner::LowPass));
+    }
+
+    #[test]
+    fn cutoff_hz_picks_high_pass_corner_when_hp_slope_enabled() {
+        let value = set_bit(
+            set_hpcf_bits(0, HPCF_Xl::ODR_SLOPE_4),
+            HP_SLOPE_XL_EN,
+            true,
+        );
+        let xl = Ctrl8Xl::new(value, SlaveAddr::Default);
+        assert_eq!(xl.cutoff_hz(400.0), (100.0, FilterCorner::HighPass));
+    }
+
+    struct PanicI2c;
+
+    impl embedded_hal::blocking::i2c::Write for PanicI2c {
+        type Error = ();
+
+        fn write(&mut self, _addr: u8, _bytes: &[u8]) -> Result<(), Self::Error> {
+            panic!("write must not be called for a rejected config");
+        }
+    }
+
+    #[test]
+    fn xl_filter_config_rejects_hp_ref_mode_without_hp_slope() {
+        let mut xl = Ctrl8Xl::new(0, SlaveAddr::Default);
+        let config = XlFilterConfig::new(HPCF_Xl::ODR_SLOPE_4).hp_ref_mode(true);
+
+        assert_eq!(
+            config.write(&mut xl, &mut PanicI2c),
+            Err(XlFilterConfigError::InvalidConfig(
+                InvalidXlFilterConfig::HpRefModeRequiresHpSlope
+            ))
+        );
+    }
+
+    #[test]
+    fn xl_filter_config_rejects_low_pass_on_6d_with_hp_slope_enabled() {
+        let mut xl = Ctrl8Xl::new(0, SlaveAddr::Default);
+        let config = XlFilterConfig::new(HPCF_Xl::ODR_SLOPE_4)
+            .hp_slope_xl_en(true)
+            .low_pass_on_6d(true);
+
+        assert_eq!(
+            config.write(&mut xl, &mut PanicI2c),
+            Err(XlFilterConfigError::InvalidConfig(
+                InvalidXlFilterConfig::LowPassOn6dRequiresHpSlopeDisabled
+            ))
+        );
+    }
+
+    #[derive(Default)]
+    struct RecordingI2c {
+        writes: u32,
+        last: Option<(u8, u8, u8)>,
+    }
+
+    impl embedded_hal::blocking::i2c::Write for RecordingI2c {
+        type Error = ();
+
+        fn write(&mut self, addr: u8, bytes: &[u8]) -> Result<(), Self::Error> {
+            self.writes += 1;
+            self.last = Some((addr, bytes[0], bytes[1]));
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn xl_filter_config_commits_the_whole_byte_in_one_write() {
+        let mut xl = Ctrl8Xl::new(0, SlaveAddr::Default);
+        let config = XlFilterConfig::new(HPCF_Xl::ODR_100).hp_slope_xl_en(true);
+        let mut i2c = RecordingI2c::default();
+
+        config.write(&mut xl, &mut i2c).unwrap();
+
+        assert_eq!(i2c.writes, 1);
+        assert_eq!(i2c.last, Some((xl.address(), ADDR, config.byte())));
+    }
+
+    #[cfg(feature = "async")]
+    mod async_tests {
+        use super::*;
+        use core::future::Future;
+        use core::pin::Pin;
+        use core::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+        use embedded_hal_async::i2c::{ErrorType, Operation};
+
+        /// Drives a future to completion. Every mock `I2c` here resolves on
+        /// its first poll, so a real executor/waker is unnecessary.
+        fn block_on<F: Future>(mut fut: F) -> F::Output {
+            const VTABLE: RawWakerVTable =
+                RawWakerVTable::new(|_| RAW_WAKER, |_| {}, |_| {}, |_| {});
+            const RAW_WAKER: RawWaker = RawWaker::new(core::ptr::null(), &VTABLE);
+            let waker = unsafe { Waker::from_raw(RAW_WAKER) };
+            let mut cx = Context::from_waker(&waker);
+            let mut fut = unsafe { Pin::new_unchecked(&mut fut) };
+            match fut.as_mut().poll(&mut cx) {
+                Poll::Ready(val) => val,
+                Poll::Pending => panic!("mock I2c must resolve synchronously"),
+            }
+        }
+
+        #[derive(Default)]
+        struct RecordingAsyncI2c {
+            writes: u32,
+            last: Option<(u8, u8, u8)>,
+        }
+
+        impl ErrorType for RecordingAsyncI2c {
+            type Error = core::convert::Infallible;
+        }
+
+        impl I2c for RecordingAsyncI2c {
+            async fn transaction(
+                &mut self,
+                address: u8,
+                operations: &mut [Operation<'_>],
+            ) -> Result<(), Self::Error> {
+                for op in operations {
+                    if let Operation::Write(bytes) = op {
+                        self.writes += 1;
+                        self.last = Some((address, bytes[0], bytes[1]));
+                    }
+                }
+                Ok(())
+            }
+        }
+
+        #[test]
+        fn set_hp_slope_xl_en_async_round_trips_the_same_byte_as_the_blocking_setter() {
+            let mut blocking_xl = Ctrl8Xl::new(0, SlaveAddr::Default);
+            let mut blocking_i2c = RecordingI2c::default();
+            blocking_xl
+                .set_hp_slope_xl_en(&mut blocking_i2c, true)
+                .unwrap();
+
+            let mut async_xl = Ctrl8Xl::new(0, SlaveAddr::Default);
+            let mut async_i2c = RecordingAsyncI2c::default();
+            block_on(async_xl.set_hp_slope_xl_en_async(&mut async_i2c, true)).unwrap();
+
+            assert!(async_xl.hp_slope_xl_en());
+            assert_eq!(async_i2c.writes, 1);
+            assert_eq!(async_i2c.last, blocking_i2c.last);
+        }
+    }
 }
 
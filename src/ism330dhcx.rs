@@ -0,0 +1,89 @@
+use embedded_hal::blocking::i2c::{Write, WriteRead};
+
+use crate::ctrl1xl::Ctrl1Xl;
+use crate::ctrl8xl::Ctrl8Xl;
+use crate::SlaveAddr;
+
+/// Sub-address of the first accelerometer output register (`OUTX_L_A`). The
+/// six bytes starting here (`OUTX_L/H_A`, `OUTY_L/H_A`, `OUTZ_L/H_A`) hold the
+/// latest accelerometer sample as little-endian `i16`s.
+const OUTX_L_A: u8 = 0x28;
+
+/// Driver for the ISM330DHCX, holding the subset of its control registers
+/// this crate currently exposes plus the I2C bus it's wired to.
+///
+/// Unlike [`Ctrl8Xl`]'s per-call `i2c` argument, the bus is owned here so
+/// this type can implement the `accelerometer` crate's traits, which assume
+/// the sensor handle can read a sample on its own (see the `lis2dh12` driver).
+pub struct Ism330Dhcx<I2C> {
+    pub ctrl1xl: Ctrl1Xl,
+    pub ctrl8xl: Ctrl8Xl,
+    i2c: I2C,
+}
+
+impl<I2C, E> Ism330Dhcx<I2C>
+where
+    I2C: WriteRead<Error = E> + Write<Error = E>,
+{
+    pub fn new(i2c: I2C, address: SlaveAddr) -> Self {
+        Ism330Dhcx {
+            ctrl1xl: Ctrl1Xl::new(0, address),
+            ctrl8xl: Ctrl8Xl::new(0, address),
+            i2c,
+        }
+    }
+
+    /// Reads the latest accelerometer sample as raw, unscaled counts.
+    pub fn read_xl_raw(&mut self) -> Result<[i16; 3], E> {
+        let mut buf = [0u8; 6];
+        self.i2c
+            .write_read(self.ctrl1xl.address(), &[OUTX_L_A], &mut buf)?;
+
+        Ok([
+            i16::from_le_bytes([buf[0], buf[1]]),
+            i16::from_le_bytes([buf[2], buf[3]]),
+            i16::from_le_bytes([buf[4], buf[5]]),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    pub struct MockI2c {
+        pub response: [u8; 6],
+    }
+
+    impl Write for MockI2c {
+        type Error = ();
+
+        fn write(&mut self, _addr: u8, _bytes: &[u8]) -> Result<(), Self::Error> {
+            Ok(())
+        }
+    }
+
+    impl WriteRead for MockI2c {
+        type Error = ();
+
+        fn write_read(
+            &mut self,
+            _addr: u8,
+            _bytes: &[u8],
+            buffer: &mut [u8],
+        ) -> Result<(), Self::Error> {
+            buffer.copy_from_slice(&self.response);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn read_xl_raw_decodes_little_endian_samples() {
+        let i2c = MockI2c {
+            response: [0x00, 0x01, 0xff, 0x7f, 0x00, 0x80],
+        };
+        let mut xl = Ism330Dhcx::new(i2c, SlaveAddr::Default);
+
+        assert_eq!(xl.read_xl_raw().unwrap(), [0x0100, i16::MAX, i16::MIN]);
+    }
+}
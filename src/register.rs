@@ -0,0 +1,33 @@
+use embedded_hal_async::i2c::I2c;
+
+/// Async counterpart of [`Register`](crate::Register).
+///
+/// Built on `embedded-hal-async`'s [`I2c`] trait so register types can be
+/// driven from an async executor (e.g. a DMA-backed I2C master) instead of
+/// blocking the whole transaction. Types that want an async API implement
+/// this alongside the blocking [`Register`](crate::Register) trait; the two
+/// share the same wire format, only the I2C trait bound differs.
+#[allow(async_fn_in_trait)]
+pub trait AsyncRegister {
+    async fn write<I2C>(&self, i2c: &mut I2C, dev: u8, addr: u8, value: u8) -> Result<(), I2C::Error>
+    where
+        I2C: I2c,
+    {
+        i2c.write(dev, &[addr, value]).await
+    }
+
+    async fn read_write<I2C>(
+        &self,
+        i2c: &mut I2C,
+        dev: u8,
+        addr: u8,
+        value: u8,
+    ) -> Result<u8, I2C::Error>
+    where
+        I2C: I2c,
+    {
+        let mut buf = [0u8; 1];
+        i2c.write_read(dev, &[addr, value], &mut buf).await?;
+        Ok(buf[0])
+    }
+}
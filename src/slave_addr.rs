@@ -0,0 +1,44 @@
+/// The ISM330DHCX's I2C slave address, selected by the level on the SDO/SA0
+/// pin. Mirrors the `SlaveAddr` type from the `lis2dh12` driver, so that
+/// addressing a sensor is expressed once instead of as a raw `u8` at every
+/// call site.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum SlaveAddr {
+    /// SDO/SA0 tied low. This is the default on most breakout boards.
+    Default,
+    /// SDO/SA0 tied to the given level. Lets two sensors share a bus.
+    Alternative(bool),
+}
+
+impl Default for SlaveAddr {
+    fn default() -> Self {
+        SlaveAddr::Default
+    }
+}
+
+impl SlaveAddr {
+    /// Resolves to the 7-bit I2C address for this SDO/SA0 configuration.
+    pub fn addr(self) -> u8 {
+        match self {
+            SlaveAddr::Default => 0x6a,
+            SlaveAddr::Alternative(false) => 0x6a,
+            SlaveAddr::Alternative(true) => 0x6b,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_resolves_to_the_sdo_low_address() {
+        assert_eq!(SlaveAddr::Default.addr(), 0x6a);
+    }
+
+    #[test]
+    fn alternative_resolves_by_the_sdo_level() {
+        assert_eq!(SlaveAddr::Alternative(false).addr(), 0x6a);
+        assert_eq!(SlaveAddr::Alternative(true).addr(), 0x6b);
+    }
+}